@@ -13,9 +13,13 @@
 
 //! Chain specification for Rundler
 
-use std::{collections::HashMap, str::FromStr, sync::Arc};
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+    sync::Arc,
+};
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use serde::{Deserialize, Serialize};
 
 use crate::{aggregator::SignatureAggregator, da::DAGasOracleType, proxy::SubmissionProxy};
@@ -34,10 +38,6 @@ pub struct ChainSpec {
     pub name: String,
     /// chain id
     pub id: u64,
-    /// entry point address for v0_6
-    pub entry_point_address_v0_6: Address,
-    /// entry point address for v0_7
-    pub entry_point_address_v0_7: Address,
     /// address of the multicall3 contract
     pub multicall3_address: Address,
 
@@ -53,10 +53,6 @@ pub struct ChainSpec {
     pub block_gas_limit: u64,
     /// Intrinsic gas cost for a transaction
     pub transaction_intrinsic_gas: u64,
-    /// Per user operation gas cost for v0.6
-    pub per_user_op_v0_6_gas: u64,
-    /// Per user operation gas cost for v0.7
-    pub per_user_op_v0_7_gas: u64,
     /// Per user operation deploy gas cost overhead, to capture
     /// deploy costs that are not metered by the entry point
     pub per_user_op_deploy_overhead_gas: u64,
@@ -70,10 +66,22 @@ pub struct ChainSpec {
     pub calldata_floor_zero_byte_gas: u64,
     /// Gas cost for a non-zero byte in calldata for the floor operation
     pub calldata_floor_non_zero_byte_gas: u64,
+    /// Gas cost for a cold account access per EIP-2929
+    pub cold_account_access_gas: u64,
+    /// Gas cost for a cold storage slot read (SLOAD) per EIP-2929
+    pub cold_sload_gas: u64,
+    /// Gas cost for a warm storage slot read per EIP-2929
+    pub warm_storage_read_gas: u64,
 
     /*
      * Gas estimation
      */
+    /// true if an EIP-2930 access list should be built for the bundle transaction to
+    /// pre-warm entry point, submission proxy, aggregator, and sender/paymaster storage,
+    /// and if cold access costs should be accounted for during gas estimation.
+    ///
+    /// Not all chains price access lists identically, so this is gated per chain.
+    pub access_list_enabled: bool,
     /// true if DA is priced in preVerificationGas
     pub da_pre_verification_gas: bool,
     /// type of gas oracle contract for pricing calldata in preVerificationGas
@@ -106,6 +114,23 @@ pub struct ChainSpec {
     /// Some chains have artificially high block gas limits but
     /// actually cap block gas usage at a lower value.
     pub congestion_trigger_usage_ratio_threshold: f64,
+    /// Number of recent blocks' `gas_used / gas_limit` ratios to average over when
+    /// computing the congestion multiplier
+    pub congestion_oracle_window_size: u64,
+    /// Exponent applied to how far the moving average usage ratio is over
+    /// `congestion_trigger_usage_ratio_threshold` when scaling the congestion multiplier.
+    /// A value of 1.0 scales linearly; higher values back-load the scaling curve so it
+    /// only ramps up sharply as usage approaches 100%.
+    pub congestion_scaling_exponent: f64,
+    /// EIP-1559 base fee max change denominator, used to project the next
+    /// block's base fee from the parent block's base fee and gas usage
+    pub base_fee_max_change_denominator: u64,
+    /// EIP-1559 elasticity multiplier, used to compute the gas target
+    /// (`parent_gas_limit / elasticity_multiplier`) for base fee projection
+    pub elasticity_multiplier: u64,
+    /// Number of blocks to project the base fee forward when building a
+    /// `maxFeePerGas` buffer for bundles that may land several blocks out
+    pub base_fee_projection_blocks: u64,
 
     /*
      * Bundle building
@@ -133,6 +158,24 @@ pub struct ChainSpec {
     /// Size of the chain history to keep to handle reorgs
     pub chain_history_size: u64,
 
+    /*
+     * Entry points
+     */
+    /// Address of the v0.6 entry point contract
+    pub entry_point_address_v0_6: Address,
+    /// Address of the v0.7 entry point contract
+    pub entry_point_address_v0_7: Address,
+    /// Per user operation gas cost charged by the v0.6 entry point
+    pub per_user_op_v0_6_gas: u64,
+    /// Per user operation gas cost charged by the v0.7 entry point
+    pub per_user_op_v0_7_gas: u64,
+    /// Registry of additional entry point configurations (e.g. an alternate deployment
+    /// address on a rollup), keyed by version, on top of the v0.6/v0.7 fields above.
+    /// Only versions named in `EntryPointVersion` (v0.6 and v0.7) can be registered.
+    /// Not deserialized with the rest of the chain spec; set it with `set_entry_points`.
+    #[serde(skip)]
+    pub entry_points: Arc<EntryPointRegistry>,
+
     /*
      * Contracts
      */
@@ -146,6 +189,14 @@ pub struct ChainSpec {
     /// Registry of submission proxies
     #[serde(skip)]
     pub submission_proxies: Arc<ContractRegistry<Arc<dyn SubmissionProxy>>>,
+
+    /*
+     * EIP-7702 delegates
+     */
+    /// Registry of EIP-7702 delegation targets that this chain will accept as the
+    /// delegation designator of a user operation's sender
+    #[serde(skip)]
+    pub eip7702_delegates: Arc<ContractRegistry<Eip7702DelegateConfig>>,
 }
 
 /// Type of oracle for estimating priority fees
@@ -159,27 +210,66 @@ pub enum PriorityFeeOracleType {
     UsageBased,
 }
 
+/// Rolling window oracle tracking recent block fullness for the `UsageBased` priority
+/// fee oracle.
+///
+/// Ingests the `gas_used / gas_limit` ratio of each new block and exposes a moving
+/// average that `ChainSpec::congestion_multiplier` uses to scale fees during congestion.
+#[derive(Clone, Debug)]
+pub struct CongestionOracle {
+    window_size: usize,
+    usage_ratios: VecDeque<f64>,
+}
+
+impl CongestionOracle {
+    /// Create a new oracle with the given rolling window size
+    pub fn new(window_size: u64) -> Self {
+        Self {
+            window_size: window_size.max(1) as usize,
+            usage_ratios: VecDeque::with_capacity(window_size.max(1) as usize),
+        }
+    }
+
+    /// Record a new block's `gas_used / gas_limit` ratio, evicting the oldest entry if the
+    /// window is full
+    pub fn record_block_usage_ratio(&mut self, usage_ratio: f64) {
+        if self.usage_ratios.len() >= self.window_size {
+            self.usage_ratios.pop_front();
+        }
+        self.usage_ratios.push_back(usage_ratio);
+    }
+
+    /// Get the moving average usage ratio over the window, or 0.0 if no blocks have been
+    /// recorded yet
+    pub fn average_usage_ratio(&self) -> f64 {
+        if self.usage_ratios.is_empty() {
+            return 0.0;
+        }
+        self.usage_ratios.iter().sum::<f64>() / self.usage_ratios.len() as f64
+    }
+}
+
 impl Default for ChainSpec {
     fn default() -> Self {
         Self {
             name: "Unknown".to_string(),
             id: 0,
             block_gas_limit: 30_000_000,
-            entry_point_address_v0_6: Address::from_str(ENTRY_POINT_ADDRESS_V0_6).unwrap(),
-            entry_point_address_v0_7: Address::from_str(ENTRY_POINT_ADDRESS_V0_7).unwrap(),
             multicall3_address: Address::from_str(MULTICALL3_ADDRESS).unwrap(),
             deposit_transfer_overhead: 30_000,
             transaction_intrinsic_gas: 21_000,
-            per_user_op_v0_6_gas: 18_300,
-            per_user_op_v0_7_gas: 19_500,
             per_user_op_deploy_overhead_gas: 0,
             per_user_op_word_gas: 4,
             calldata_zero_byte_gas: 4,
             calldata_non_zero_byte_gas: 16,
             calldata_floor_zero_byte_gas: 0,
             calldata_floor_non_zero_byte_gas: 0,
+            cold_account_access_gas: 2600,
+            cold_sload_gas: 2100,
+            warm_storage_read_gas: 100,
             eip1559_enabled: true,
             eip7702_enabled: false,
+            access_list_enabled: false,
             da_pre_verification_gas: false,
             da_gas_oracle_type: DAGasOracleType::default(),
             da_gas_oracle_contract_address: Address::ZERO,
@@ -188,14 +278,25 @@ impl Default for ChainSpec {
             min_max_priority_fee_per_gas: 0,
             max_max_priority_fee_per_gas: u64::MAX,
             congestion_trigger_usage_ratio_threshold: 0.75,
+            congestion_oracle_window_size: 10,
+            congestion_scaling_exponent: 1.0,
+            base_fee_max_change_denominator: 8,
+            elasticity_multiplier: 2,
+            base_fee_projection_blocks: 3,
             max_transaction_size_bytes: 131072, // 128 KiB
             bundle_max_send_interval_millis: 1000,
             flashbots_enabled: false,
             flashbots_relay_url: None,
             bloxroute_enabled: false,
             chain_history_size: 64,
+            entry_point_address_v0_6: Address::from_str(ENTRY_POINT_ADDRESS_V0_6).unwrap(),
+            entry_point_address_v0_7: Address::from_str(ENTRY_POINT_ADDRESS_V0_7).unwrap(),
+            per_user_op_v0_6_gas: 18_300,
+            per_user_op_v0_7_gas: 19_500,
+            entry_points: Arc::new(EntryPointRegistry::default()),
             signature_aggregators: Arc::new(ContractRegistry::default()),
             submission_proxies: Arc::new(ContractRegistry::default()),
+            eip7702_delegates: Arc::new(ContractRegistry::default()),
         }
     }
 }
@@ -226,6 +327,16 @@ impl ChainSpec {
         self.per_user_op_word_gas as u128
     }
 
+    /// Get the entry point address for v0_6
+    pub fn entry_point_address_v0_6(&self) -> Address {
+        self.entry_point_address_v0_6
+    }
+
+    /// Get the entry point address for v0_7
+    pub fn entry_point_address_v0_7(&self) -> Address {
+        self.entry_point_address_v0_7
+    }
+
     /// Get the per user operation v0_6 gas
     pub fn per_user_op_v0_6_gas(&self) -> u128 {
         self.per_user_op_v0_6_gas as u128
@@ -236,6 +347,20 @@ impl ChainSpec {
         self.per_user_op_v0_7_gas as u128
     }
 
+    /// Resolve the entry point version for a given address, checking the v0_6/v0_7 fields
+    /// before falling back to any additional versions registered in `entry_points`.
+    fn resolve_entry_point_version(&self, entry_point: &Address) -> Option<EntryPointVersion> {
+        if *entry_point == self.entry_point_address_v0_6 {
+            Some(EntryPointVersion::V0_6)
+        } else if *entry_point == self.entry_point_address_v0_7 {
+            Some(EntryPointVersion::V0_7)
+        } else {
+            self.entry_points
+                .get_by_address(entry_point)
+                .map(|(version, _)| version)
+        }
+    }
+
     /// Get the calldata zero byte gas
     pub fn calldata_zero_byte_gas(&self) -> u128 {
         self.calldata_zero_byte_gas as u128
@@ -261,11 +386,187 @@ impl ChainSpec {
         self.per_user_op_deploy_overhead_gas as u128
     }
 
+    /// Get the total per user operation deploy overhead gas for a specific entry point,
+    /// combining the chain-wide overhead with any overhead specific to that entry point
+    /// version's registered config.
+    pub fn per_user_op_deploy_overhead_gas_for_entry_point(&self, entry_point: Address) -> u128 {
+        let per_version_overhead = self
+            .entry_points
+            .get_by_address(&entry_point)
+            .map(|(_, config)| config.per_user_op_deploy_overhead_gas as u128)
+            .unwrap_or(0);
+        self.per_user_op_deploy_overhead_gas() + per_version_overhead
+    }
+
+    /// Get the cold account access gas cost
+    pub fn cold_account_access_gas(&self) -> u128 {
+        self.cold_account_access_gas as u128
+    }
+
+    /// Get the cold storage slot read gas cost
+    pub fn cold_sload_gas(&self) -> u128 {
+        self.cold_sload_gas as u128
+    }
+
+    /// Get the warm storage read gas cost
+    pub fn warm_storage_read_gas(&self) -> u128 {
+        self.warm_storage_read_gas as u128
+    }
+
+    /// Calculate the cold access gas overhead for a user operation that touches
+    /// `num_cold_accounts` accounts and `num_cold_slots` storage slots for the first time.
+    pub fn cold_access_overhead_gas(&self, num_cold_accounts: u64, num_cold_slots: u64) -> u128 {
+        (num_cold_accounts as u128 * self.cold_account_access_gas())
+            + (num_cold_slots as u128 * self.cold_sload_gas())
+    }
+
+    /// Build the EIP-2930 access list to attach to an outgoing bundle transaction, pre-warming
+    /// the entry point, the submission proxy actually used by this bundle (if any), the
+    /// aggregator (if any), and the sender and paymaster storage slots used during
+    /// validation.
+    ///
+    /// Only the proxy used by this bundle is included: listing every registered proxy would
+    /// add cold-access entries with no warm-access saving and work against the gas savings
+    /// this access list is meant to provide.
+    ///
+    /// Returns an empty access list if `access_list_enabled` is false for this chain.
+    pub fn build_bundle_access_list(
+        &self,
+        entry_point: Address,
+        submission_proxy: Option<Address>,
+        aggregator: Option<Address>,
+        sender: (Address, Vec<B256>),
+        paymaster: Option<(Address, Vec<B256>)>,
+    ) -> Vec<AccessListEntry> {
+        if !self.access_list_enabled {
+            return Vec::new();
+        }
+
+        let mut entries = vec![AccessListEntry {
+            address: entry_point,
+            storage_keys: vec![],
+        }];
+        if let Some(submission_proxy) = submission_proxy {
+            entries.push(AccessListEntry {
+                address: submission_proxy,
+                storage_keys: vec![],
+            });
+        }
+        if let Some(aggregator) = aggregator {
+            entries.push(AccessListEntry {
+                address: aggregator,
+                storage_keys: vec![],
+            });
+        }
+        let (sender, sender_storage_keys) = sender;
+        entries.push(AccessListEntry {
+            address: sender,
+            storage_keys: sender_storage_keys,
+        });
+        if let Some((paymaster, paymaster_storage_keys)) = paymaster {
+            entries.push(AccessListEntry {
+                address: paymaster,
+                storage_keys: paymaster_storage_keys,
+            });
+        }
+        entries
+    }
+
     /// Calculate a multiple of the block limit
     pub fn block_gas_limit_mult(&self, mult: f64) -> u128 {
         (self.block_gas_limit as f64 * mult) as u128
     }
 
+    /// Project the next block's base fee from the parent block's base fee and gas usage,
+    /// following the EIP-1559 base fee adjustment formula.
+    pub fn next_block_base_fee(
+        &self,
+        parent_base_fee: u128,
+        parent_gas_used: u64,
+        parent_gas_limit: u64,
+    ) -> u128 {
+        let denominator = self.base_fee_max_change_denominator as u128;
+        // A zero elasticity multiplier or denominator is a degenerate config; there's no
+        // sane adjustment to make, so leave the base fee unchanged rather than panic.
+        if self.elasticity_multiplier == 0 || denominator == 0 {
+            return parent_base_fee;
+        }
+
+        let gas_target = parent_gas_limit / self.elasticity_multiplier;
+        // A tiny or zero parent gas limit can still round `gas_target` down to zero.
+        if gas_target == 0 {
+            return parent_base_fee;
+        }
+        let gas_target_128 = gas_target as u128;
+
+        match parent_gas_used.cmp(&gas_target) {
+            std::cmp::Ordering::Greater => {
+                let gas_used_delta = (parent_gas_used - gas_target) as u128;
+                let base_fee_delta = std::cmp::max(
+                    parent_base_fee * gas_used_delta / gas_target_128 / denominator,
+                    1,
+                );
+                parent_base_fee + base_fee_delta
+            }
+            std::cmp::Ordering::Less => {
+                let gas_used_delta = (gas_target - parent_gas_used) as u128;
+                let base_fee_delta =
+                    parent_base_fee * gas_used_delta / gas_target_128 / denominator;
+                parent_base_fee.saturating_sub(base_fee_delta)
+            }
+            std::cmp::Ordering::Equal => parent_base_fee,
+        }
+    }
+
+    /// Project a worst-case `maxFeePerGas` buffer by compounding the maximum possible
+    /// base fee increase (assuming every intervening block is full) over
+    /// `base_fee_projection_blocks` blocks.
+    ///
+    /// This gives bundles headroom to land several blocks out without being priced out
+    /// by a base fee spike. `congestion_multiplier` is folded in on top of the worst-case
+    /// compounding so base fee projection and priority fee scaling share the same view of
+    /// current network congestion; pass `1.0` to disable it.
+    pub fn project_max_base_fee(&self, base_fee: u128, congestion_multiplier: f64) -> u128 {
+        let denominator = self.base_fee_max_change_denominator as f64;
+        let growth_per_block = 1.0 + (1.0 / denominator);
+        let compounded = growth_per_block.powi(self.base_fee_projection_blocks as i32);
+        ((base_fee as f64) * compounded * congestion_multiplier).ceil() as u128
+    }
+
+    /// Compute the congestion multiplier from a moving average of recent block usage ratios.
+    ///
+    /// Returns `1.0` (no congestion adjustment) when the average is at or below
+    /// `congestion_trigger_usage_ratio_threshold`, or when that threshold is `1.0` or more;
+    /// otherwise scales up proportionally to how far over the threshold the network is,
+    /// raised to `congestion_scaling_exponent`.
+    pub fn congestion_multiplier(&self, average_usage_ratio: f64) -> f64 {
+        let threshold = self.congestion_trigger_usage_ratio_threshold;
+        if average_usage_ratio <= threshold || threshold >= 1.0 {
+            return 1.0;
+        }
+        let over = ((average_usage_ratio - threshold) / (1.0 - threshold)).clamp(0.0, 1.0);
+        1.0 + over.powf(self.congestion_scaling_exponent)
+    }
+
+    /// Scale `min_max_priority_fee_per_gas` upward toward `max_max_priority_fee_per_gas`
+    /// based on the current congestion multiplier, for use by the `UsageBased` priority fee
+    /// oracle.
+    pub fn congested_min_max_priority_fee_per_gas(&self, average_usage_ratio: f64) -> u128 {
+        let multiplier = self.congestion_multiplier(average_usage_ratio);
+        let min = self.min_max_priority_fee_per_gas() as f64;
+        let max = self.max_max_priority_fee_per_gas() as f64;
+        let scaled = min + (max - min) * (multiplier - 1.0);
+        scaled.clamp(min, max) as u128
+    }
+
+    /// Set the registry of additional entry point configurations, e.g. to register a custom
+    /// entry point deployment (such as an alternate address on a rollup) without needing to
+    /// override the v0_6/v0_7 fields. Only versions named in `EntryPointVersion` can be
+    /// registered.
+    pub fn set_entry_points(&mut self, entry_points: Arc<EntryPointRegistry>) {
+        self.entry_points = entry_points;
+    }
+
     /// Set signature aggregators
     pub fn set_signature_aggregators(
         &mut self,
@@ -300,10 +601,138 @@ impl ChainSpec {
         self.submission_proxies.contracts.keys()
     }
 
-    /// Check if the chain supports EIP-7702
-    pub fn supports_eip7702(&self, entry_point: Address) -> bool {
-        self.eip7702_enabled || entry_point == self.entry_point_address_v0_7
+    /// Check if the chain supports EIP-7702 for the given entry point and delegation target.
+    ///
+    /// The entry point must be capable of EIP-7702 (either globally via `eip7702_enabled` or
+    /// because its version supports it by default), and the delegation target must be
+    /// allow-listed in the `eip7702_delegates` registry.
+    ///
+    /// BREAKING: `eip7702_delegates` defaults empty, so this returns `false` for every
+    /// delegate until an operator registers at least one via `set_eip7702_delegates`, even
+    /// on chains that already set `eip7702_enabled = true`. This is intentional — the
+    /// registry exists to vet delegate implementations before they're sponsored — but it
+    /// means upgrading onto this check requires populating the registry to keep 7702
+    /// working.
+    pub fn supports_eip7702(&self, entry_point: Address, delegate: Address) -> bool {
+        let entry_point_capable = self.eip7702_enabled
+            || self
+                .resolve_entry_point_version(&entry_point)
+                .is_some_and(|version| version.supports_eip7702_by_default());
+
+        entry_point_capable && self.is_eip7702_delegate_allowed(&delegate)
+    }
+
+    /// Set the allow-listed EIP-7702 delegation targets
+    pub fn set_eip7702_delegates(
+        &mut self,
+        eip7702_delegates: Arc<ContractRegistry<Eip7702DelegateConfig>>,
+    ) {
+        self.eip7702_delegates = eip7702_delegates;
+    }
+
+    /// Check if a delegation target is allow-listed for EIP-7702
+    pub fn is_eip7702_delegate_allowed(&self, delegate: &Address) -> bool {
+        self.eip7702_delegates.get(delegate).is_some()
+    }
+
+    /// Get the configuration for an allow-listed EIP-7702 delegation target
+    pub fn get_eip7702_delegate(&self, delegate: &Address) -> Option<&Eip7702DelegateConfig> {
+        self.eip7702_delegates.get(delegate)
     }
+
+    /// Get the per user operation deploy overhead gas, including the authorization overhead
+    /// of the given EIP-7702 delegation target, if any
+    pub fn per_user_op_deploy_overhead_gas_for_delegate(&self, delegate: &Address) -> u128 {
+        let delegate_overhead = self
+            .eip7702_delegates
+            .get(delegate)
+            .map(|config| config.authorization_gas_overhead as u128)
+            .unwrap_or(0);
+
+        self.per_user_op_deploy_overhead_gas() + delegate_overhead
+    }
+}
+
+/// A single entry in an EIP-2930 access list
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessListEntry {
+    /// Address being accessed
+    pub address: Address,
+    /// Storage slots on `address` being accessed
+    pub storage_keys: Vec<B256>,
+}
+
+/// Identifies a deployed version of the ERC-4337 entry point contract
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EntryPointVersion {
+    /// v0.6
+    V0_6,
+    /// v0.7
+    V0_7,
+}
+
+impl EntryPointVersion {
+    /// Whether user operations submitted through this entry point version support EIP-7702
+    /// by default, without requiring the chain-wide `eip7702_enabled` override.
+    pub fn supports_eip7702_by_default(&self) -> bool {
+        matches!(self, EntryPointVersion::V0_7)
+    }
+}
+
+/// Configuration for a single entry point deployment
+#[derive(Clone, Debug)]
+pub struct EntryPointConfig {
+    /// Address of the entry point contract
+    pub address: Address,
+    /// Per user operation gas cost charged by this entry point version
+    pub per_user_op_gas: u64,
+    /// Per user operation deploy gas cost overhead for this entry point version, to capture
+    /// deploy costs that are not metered by the entry point
+    pub per_user_op_deploy_overhead_gas: u64,
+}
+
+/// Registry of additional entry point configurations, keyed by version, with address-based
+/// lookup
+///
+/// This layers on top of `ChainSpec`'s `entry_point_address_v0_6`/`v0_7` fields: operators
+/// can register custom entry point deployments (e.g. an alternate address on a rollup) for
+/// the versions in `EntryPointVersion`. Each version holds one configuration; a version not
+/// in `EntryPointVersion` cannot be registered.
+#[derive(Debug, Default)]
+pub struct EntryPointRegistry {
+    by_version: HashMap<EntryPointVersion, EntryPointConfig>,
+}
+
+impl EntryPointRegistry {
+    /// Register an entry point configuration for a version
+    pub fn register(&mut self, version: EntryPointVersion, config: EntryPointConfig) {
+        self.by_version.insert(version, config);
+    }
+
+    /// Get the entry point configuration for a version
+    pub fn get(&self, version: EntryPointVersion) -> Option<&EntryPointConfig> {
+        self.by_version.get(&version)
+    }
+
+    /// Get the entry point version and configuration registered for an address
+    pub fn get_by_address(
+        &self,
+        address: &Address,
+    ) -> Option<(EntryPointVersion, &EntryPointConfig)> {
+        self.by_version
+            .iter()
+            .find(|(_, config)| &config.address == address)
+            .map(|(version, config)| (*version, config))
+    }
+}
+
+/// Configuration for an allow-listed EIP-7702 delegation target
+#[derive(Clone, Debug)]
+pub struct Eip7702DelegateConfig {
+    /// Gas overhead of authorizing and deploying this delegate, folded into the
+    /// deploy-overhead portion of gas estimation for sponsored 7702 ops
+    pub authorization_gas_overhead: u64,
 }
 
 /// Registry of contracts
@@ -380,3 +809,336 @@ where
         U::from_with_spec(self, chain_spec)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supports_eip7702_denies_unregistered_delegate_even_when_entry_point_capable() {
+        let spec = ChainSpec::default();
+        let entry_point_v0_7 = spec.entry_point_address_v0_7();
+        let delegate = Address::with_last_byte(1);
+
+        assert!(!spec.supports_eip7702(entry_point_v0_7, delegate));
+    }
+
+    #[test]
+    fn supports_eip7702_denies_unregistered_delegate_even_when_globally_enabled() {
+        let mut spec = ChainSpec {
+            eip7702_enabled: true,
+            ..ChainSpec::default()
+        };
+        let entry_point_v0_6 = spec.entry_point_address_v0_6();
+        let delegate = Address::with_last_byte(2);
+
+        assert!(!spec.supports_eip7702(entry_point_v0_6, delegate));
+
+        let mut registry = ContractRegistry::default();
+        registry.register(
+            delegate,
+            Eip7702DelegateConfig {
+                authorization_gas_overhead: 25_000,
+            },
+        );
+        spec.set_eip7702_delegates(Arc::new(registry));
+
+        assert!(spec.supports_eip7702(entry_point_v0_6, delegate));
+    }
+
+    #[test]
+    fn next_block_base_fee_increases_when_above_gas_target() {
+        let spec = ChainSpec::default(); // elasticity_multiplier: 2, base_fee_max_change_denominator: 8
+        let next = spec.next_block_base_fee(100, 15_000_000, 20_000_000);
+        assert_eq!(next, 106);
+    }
+
+    #[test]
+    fn next_block_base_fee_decreases_when_below_gas_target() {
+        let spec = ChainSpec::default();
+        let next = spec.next_block_base_fee(100, 5_000_000, 20_000_000);
+        assert_eq!(next, 94);
+    }
+
+    #[test]
+    fn next_block_base_fee_unchanged_at_gas_target() {
+        let spec = ChainSpec::default();
+        let next = spec.next_block_base_fee(100, 10_000_000, 20_000_000);
+        assert_eq!(next, 100);
+    }
+
+    #[test]
+    fn next_block_base_fee_does_not_panic_on_degenerate_config() {
+        let spec = ChainSpec {
+            elasticity_multiplier: 0,
+            ..ChainSpec::default()
+        };
+        assert_eq!(spec.next_block_base_fee(100, 1, 20_000_000), 100);
+
+        let spec = ChainSpec::default();
+        // parent_gas_limit / elasticity_multiplier rounds down to a zero gas target
+        assert_eq!(spec.next_block_base_fee(100, 1, 1), 100);
+    }
+
+    #[test]
+    fn project_max_base_fee_compounds_worst_case_over_projection_blocks() {
+        let spec = ChainSpec::default(); // denominator: 8, base_fee_projection_blocks: 3
+        assert_eq!(spec.project_max_base_fee(1000, 1.0), 1424);
+        assert_eq!(spec.project_max_base_fee(1000, 2.0), 2848);
+    }
+
+    #[test]
+    fn congestion_multiplier_is_unscaled_below_or_at_threshold() {
+        let spec = ChainSpec::default(); // threshold: 0.75
+        assert_eq!(spec.congestion_multiplier(0.5), 1.0);
+        assert_eq!(spec.congestion_multiplier(0.75), 1.0);
+    }
+
+    #[test]
+    fn congestion_multiplier_scales_proportionally_above_threshold() {
+        let spec = ChainSpec::default(); // threshold: 0.75, exponent: 1.0
+        assert_eq!(spec.congestion_multiplier(0.875), 1.5);
+        assert_eq!(spec.congestion_multiplier(1.0), 2.0);
+        // usage can't exceed 1.0 in practice, but the multiplier must still clamp
+        assert_eq!(spec.congestion_multiplier(1.5), 2.0);
+    }
+
+    #[test]
+    fn congestion_multiplier_disabled_when_threshold_is_at_or_above_one() {
+        let spec = ChainSpec {
+            congestion_trigger_usage_ratio_threshold: 1.0,
+            ..ChainSpec::default()
+        };
+        assert_eq!(spec.congestion_multiplier(1.5), 1.0);
+    }
+
+    #[test]
+    fn congested_min_max_priority_fee_per_gas_scales_between_bounds() {
+        let spec = ChainSpec {
+            min_max_priority_fee_per_gas: 1_000_000,
+            max_max_priority_fee_per_gas: 5_000_000,
+            congestion_trigger_usage_ratio_threshold: 0.75,
+            congestion_scaling_exponent: 1.0,
+            ..ChainSpec::default()
+        };
+        assert_eq!(spec.congested_min_max_priority_fee_per_gas(0.5), 1_000_000);
+        assert_eq!(
+            spec.congested_min_max_priority_fee_per_gas(0.875),
+            3_000_000
+        );
+        assert_eq!(spec.congested_min_max_priority_fee_per_gas(1.0), 5_000_000);
+    }
+
+    #[test]
+    fn congestion_oracle_averages_over_a_rolling_window() {
+        let mut oracle = CongestionOracle::new(3);
+        assert_eq!(oracle.average_usage_ratio(), 0.0);
+
+        oracle.record_block_usage_ratio(0.1);
+        oracle.record_block_usage_ratio(0.2);
+        oracle.record_block_usage_ratio(0.3);
+        oracle.record_block_usage_ratio(0.4); // evicts 0.1
+
+        let avg = oracle.average_usage_ratio();
+        assert!((avg - 0.3).abs() < 1e-9, "expected ~0.3, got {avg}");
+    }
+
+    #[test]
+    fn supports_eip7702_denies_entry_point_incapable_of_7702() {
+        let mut spec = ChainSpec::default();
+        let entry_point_v0_6 = spec.entry_point_address_v0_6();
+        let delegate = Address::with_last_byte(3);
+
+        let mut registry = ContractRegistry::default();
+        registry.register(
+            delegate,
+            Eip7702DelegateConfig {
+                authorization_gas_overhead: 0,
+            },
+        );
+        spec.set_eip7702_delegates(Arc::new(registry));
+
+        // v0_6 doesn't support 7702 by default and eip7702_enabled is false
+        assert!(!spec.supports_eip7702(entry_point_v0_6, delegate));
+    }
+
+    #[test]
+    fn build_bundle_access_list_returns_empty_when_disabled() {
+        let spec = ChainSpec {
+            access_list_enabled: false,
+            ..ChainSpec::default()
+        };
+        let entry_point = spec.entry_point_address_v0_7();
+        let sender = Address::with_last_byte(1);
+
+        let access_list = spec.build_bundle_access_list(
+            entry_point,
+            Some(Address::with_last_byte(2)),
+            Some(Address::with_last_byte(3)),
+            (sender, vec![B256::with_last_byte(1)]),
+            Some((Address::with_last_byte(4), vec![B256::with_last_byte(2)])),
+        );
+
+        assert!(access_list.is_empty());
+    }
+
+    #[test]
+    fn build_bundle_access_list_includes_only_entry_point_and_sender_when_optionals_absent() {
+        let spec = ChainSpec {
+            access_list_enabled: true,
+            ..ChainSpec::default()
+        };
+        let entry_point = spec.entry_point_address_v0_7();
+        let sender = Address::with_last_byte(1);
+        let sender_storage_keys = vec![B256::with_last_byte(1)];
+
+        let access_list = spec.build_bundle_access_list(
+            entry_point,
+            None,
+            None,
+            (sender, sender_storage_keys.clone()),
+            None,
+        );
+
+        assert_eq!(
+            access_list,
+            vec![
+                AccessListEntry {
+                    address: entry_point,
+                    storage_keys: vec![],
+                },
+                AccessListEntry {
+                    address: sender,
+                    storage_keys: sender_storage_keys,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn build_bundle_access_list_includes_proxy_aggregator_and_paymaster_when_present() {
+        let spec = ChainSpec {
+            access_list_enabled: true,
+            ..ChainSpec::default()
+        };
+        let entry_point = spec.entry_point_address_v0_7();
+        let submission_proxy = Address::with_last_byte(2);
+        let aggregator = Address::with_last_byte(3);
+        let sender = Address::with_last_byte(1);
+        let sender_storage_keys = vec![B256::with_last_byte(1)];
+        let paymaster = Address::with_last_byte(4);
+        let paymaster_storage_keys = vec![B256::with_last_byte(2)];
+
+        let access_list = spec.build_bundle_access_list(
+            entry_point,
+            Some(submission_proxy),
+            Some(aggregator),
+            (sender, sender_storage_keys.clone()),
+            Some((paymaster, paymaster_storage_keys.clone())),
+        );
+
+        assert_eq!(
+            access_list,
+            vec![
+                AccessListEntry {
+                    address: entry_point,
+                    storage_keys: vec![],
+                },
+                AccessListEntry {
+                    address: submission_proxy,
+                    storage_keys: vec![],
+                },
+                AccessListEntry {
+                    address: aggregator,
+                    storage_keys: vec![],
+                },
+                AccessListEntry {
+                    address: sender,
+                    storage_keys: sender_storage_keys,
+                },
+                AccessListEntry {
+                    address: paymaster,
+                    storage_keys: paymaster_storage_keys,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn cold_access_overhead_gas_computes_per_account_and_per_slot_cost() {
+        let spec = ChainSpec::default(); // cold_account_access_gas: 2600, cold_sload_gas: 2100
+
+        assert_eq!(spec.cold_access_overhead_gas(0, 0), 0);
+        assert_eq!(spec.cold_access_overhead_gas(1, 0), 2600);
+        assert_eq!(spec.cold_access_overhead_gas(0, 1), 2100);
+        assert_eq!(spec.cold_access_overhead_gas(2, 3), 2 * 2600 + 3 * 2100);
+    }
+
+    #[test]
+    fn entry_point_registry_get_by_address_returns_none_for_unknown_address() {
+        let registry = EntryPointRegistry::default();
+        let unknown = Address::with_last_byte(9);
+
+        assert!(registry.get_by_address(&unknown).is_none());
+    }
+
+    #[test]
+    fn entry_point_registry_get_by_address_finds_a_registered_custom_entry_point() {
+        let mut registry = EntryPointRegistry::default();
+        let custom_version = EntryPointVersion::V0_6;
+        let custom_address = Address::with_last_byte(42);
+        registry.register(
+            custom_version,
+            EntryPointConfig {
+                address: custom_address,
+                per_user_op_gas: 20_000,
+                per_user_op_deploy_overhead_gas: 5_000,
+            },
+        );
+
+        let (version, config) = registry.get_by_address(&custom_address).unwrap();
+        assert_eq!(version, custom_version);
+        assert_eq!(config.address, custom_address);
+        assert_eq!(config.per_user_op_gas, 20_000);
+        assert_eq!(config.per_user_op_deploy_overhead_gas, 5_000);
+    }
+
+    #[test]
+    fn per_user_op_deploy_overhead_gas_for_entry_point_defaults_to_chain_wide_overhead() {
+        let spec = ChainSpec {
+            per_user_op_deploy_overhead_gas: 1_000,
+            ..ChainSpec::default()
+        };
+        let unregistered_entry_point = Address::with_last_byte(9);
+
+        assert_eq!(
+            spec.per_user_op_deploy_overhead_gas_for_entry_point(unregistered_entry_point),
+            1_000
+        );
+    }
+
+    #[test]
+    fn per_user_op_deploy_overhead_gas_for_entry_point_adds_registered_version_overhead() {
+        let mut spec = ChainSpec {
+            per_user_op_deploy_overhead_gas: 1_000,
+            ..ChainSpec::default()
+        };
+        let custom_entry_point = Address::with_last_byte(42);
+
+        let mut registry = EntryPointRegistry::default();
+        registry.register(
+            EntryPointVersion::V0_6,
+            EntryPointConfig {
+                address: custom_entry_point,
+                per_user_op_gas: 20_000,
+                per_user_op_deploy_overhead_gas: 500,
+            },
+        );
+        spec.set_entry_points(Arc::new(registry));
+
+        assert_eq!(
+            spec.per_user_op_deploy_overhead_gas_for_entry_point(custom_entry_point),
+            1_500
+        );
+    }
+}